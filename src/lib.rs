@@ -2,7 +2,8 @@ use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use pyo3::wrap_pyfunction;
 use std::str::FromStr;
-use uuid::Uuid;
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::{Context, Timestamp, Uuid};
 
 /// UUID submodule for Rusthonian
 #[pymodule]
@@ -11,7 +12,12 @@ fn uuid_module(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyUuid>()?;
     
     // Register utility functions
+    m.add_function(wrap_pyfunction!(new_v1, m)?)?;
     m.add_function(wrap_pyfunction!(new_v4, m)?)?;
+    m.add_function(wrap_pyfunction!(new_v3, m)?)?;
+    m.add_function(wrap_pyfunction!(new_v5, m)?)?;
+    m.add_function(wrap_pyfunction!(new_v6, m)?)?;
+    m.add_function(wrap_pyfunction!(new_v7, m)?)?;
     m.add_function(wrap_pyfunction!(parse_str, m)?)?;
     m.add_function(wrap_pyfunction!(nil, m)?)?;
     m.add_function(wrap_pyfunction!(max, m)?)?;
@@ -47,9 +53,12 @@ impl PyUuid {
 #[pymethods]
 impl PyUuid {
     /// Create a new UUID from a string
+    ///
+    /// Accepts the simple, hyphenated, braced, and URN forms transparently, with hex digits
+    /// in either case; the `urn:uuid:` prefix must be lowercase.
     #[new]
     fn new_from_str(s: &str) -> PyResult<Self> {
-        let uuid = Uuid::from_str(s)
+        let uuid = Uuid::from_str(s.trim())
             .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
         Ok(PyUuid::new(uuid))
     }
@@ -78,7 +87,43 @@ impl PyUuid {
     fn from_u64_pair(high: u64, low: u64) -> Self {
         PyUuid::new(Uuid::from_u64_pair(high, low))
     }
-    
+
+    /// Create a new name-based (v3, MD5) UUID from a namespace and a name
+    #[staticmethod]
+    fn new_v3(namespace: &PyUuid, name: &str) -> Self {
+        PyUuid::new(Uuid::new_v3(&namespace.inner, name.as_bytes()))
+    }
+
+    /// Create a new name-based (v5, SHA-1) UUID from a namespace and a name
+    #[staticmethod]
+    fn new_v5(namespace: &PyUuid, name: &str) -> Self {
+        PyUuid::new(Uuid::new_v5(&namespace.inner, name.as_bytes()))
+    }
+
+    /// Create a new time-based (v1) UUID, optionally pinning the node id / clock sequence
+    #[staticmethod]
+    #[pyo3(signature = (node_id=None, clock_seq=None))]
+    fn new_v1(node_id: Option<&PyBytes>, clock_seq: Option<u16>) -> PyResult<Self> {
+        let node = resolve_node_id(node_id)?;
+        let ts = now_timestamp(clock_seq);
+        Ok(PyUuid::new(Uuid::new_v1(ts, &node)))
+    }
+
+    /// Create a new reordered time-based (v6) UUID, optionally pinning the node id / clock sequence
+    #[staticmethod]
+    #[pyo3(signature = (node_id=None, clock_seq=None))]
+    fn new_v6(node_id: Option<&PyBytes>, clock_seq: Option<u16>) -> PyResult<Self> {
+        let node = resolve_node_id(node_id)?;
+        let ts = now_timestamp(clock_seq);
+        Ok(PyUuid::new(Uuid::new_v6(ts, &node)))
+    }
+
+    /// Create a new Unix-timestamp-based (v7) UUID
+    #[staticmethod]
+    fn new_v7() -> Self {
+        PyUuid::new(Uuid::now_v7())
+    }
+
     /// Get UUID as string
     fn __str__(&self) -> PyResult<String> {
         Ok(self.inner.to_string())
@@ -88,7 +133,27 @@ impl PyUuid {
     fn __repr__(&self) -> PyResult<String> {
         Ok(format!("UUID('{}')", self.inner))
     }
-    
+
+    /// Format as 32 lowercase hex characters, no dashes, e.g. `"a0eebc999c0b4ef8bb6d6bb9bd380a11"`
+    fn to_simple(&self) -> PyResult<String> {
+        Ok(self.inner.simple().to_string())
+    }
+
+    /// Format as the canonical hyphenated form, e.g. `"a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"`
+    fn to_hyphenated(&self) -> PyResult<String> {
+        Ok(self.inner.hyphenated().to_string())
+    }
+
+    /// Format wrapped in braces, e.g. `"{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}"`
+    fn to_braced(&self) -> PyResult<String> {
+        Ok(self.inner.braced().to_string())
+    }
+
+    /// Format as a URN, e.g. `"urn:uuid:a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"`
+    fn to_urn(&self) -> PyResult<String> {
+        Ok(self.inner.urn().to_string())
+    }
+
     /// Get UUID as bytes
     fn bytes(&self) -> PyResult<Vec<u8>> {
         Ok(self.inner.as_bytes().to_vec())
@@ -104,12 +169,66 @@ impl PyUuid {
         Ok(self.inner.as_u64_pair())
     }
     
+    /// Get the embedded creation time as (seconds, subsec_nanos) since the Unix epoch
+    ///
+    /// Returns `None` for versions (v3, v4, v5) that carry no timestamp.
+    fn get_timestamp(&self) -> PyResult<Option<(u64, u32)>> {
+        Ok(self.inner.get_timestamp().map(|ts| ts.to_unix()))
+    }
+
+    /// Get the embedded creation time in milliseconds since the Unix epoch
+    fn unix_ms(&self) -> PyResult<Option<u64>> {
+        Ok(self.inner.get_timestamp().map(|ts| {
+            let (secs, nanos) = ts.to_unix();
+            secs * 1000 + (nanos as u64) / 1_000_000
+        }))
+    }
+
     /// Get UUID version
     fn version(&self) -> PyResult<Option<u8>> {
-        // TODO: Implement proper version extraction
-        Ok(None)
+        match self.inner.get_variant() {
+            uuid::Variant::RFC4122 => Ok(Some(self.inner.get_version_num() as u8)),
+            _ => Ok(None),
+        }
     }
-    
+
+    /// The UUID as a 128-bit integer
+    #[getter]
+    fn int(&self) -> PyResult<u128> {
+        Ok(self.inner.as_u128())
+    }
+
+    /// The UUID as 32 lowercase hex characters, no dashes
+    #[getter]
+    fn hex(&self) -> PyResult<String> {
+        Ok(self.inner.simple().to_string())
+    }
+
+    /// The UUID as a `urn:uuid:`-prefixed string
+    #[getter]
+    fn urn(&self) -> PyResult<String> {
+        Ok(self.inner.urn().to_string())
+    }
+
+    /// The six fields making up the UUID, as in Python's stdlib `uuid.UUID.fields`
+    #[getter]
+    fn fields(&self) -> PyResult<(u32, u16, u16, u8, u8, u64)> {
+        let (time_low, time_mid, time_hi_version, rest) = self.inner.as_fields();
+        let clock_seq_hi_variant = rest[0];
+        let clock_seq_low = rest[1];
+        let mut node_bytes = [0u8; 8];
+        node_bytes[2..].copy_from_slice(&rest[2..8]);
+        let node = u64::from_be_bytes(node_bytes);
+        Ok((
+            time_low,
+            time_mid,
+            time_hi_version,
+            clock_seq_hi_variant,
+            clock_seq_low,
+            node,
+        ))
+    }
+
     /// Get UUID variant
     fn variant(&self) -> PyResult<&'static str> {
         match self.inner.get_variant() {
@@ -169,10 +288,76 @@ fn new_v4() -> PyResult<PyUuid> {
     Ok(PyUuid::new(uuid))
 }
 
+/// Resolve an explicit 6-byte node id, or draw a random one when none is given
+fn resolve_node_id(node_id: Option<&PyBytes>) -> PyResult<[u8; 6]> {
+    match node_id {
+        Some(bytes) => {
+            let slice = bytes.as_bytes();
+            if slice.len() != 6 {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "node_id must be exactly 6 bytes",
+                ));
+            }
+            let mut node = [0u8; 6];
+            node.copy_from_slice(slice);
+            Ok(node)
+        }
+        None => Ok(rand::random()),
+    }
+}
+
+/// Build a `Timestamp` for the current time, using an explicit clock sequence or a random one
+fn now_timestamp(clock_seq: Option<u16>) -> Timestamp {
+    let context = Context::new(clock_seq.unwrap_or_else(rand::random));
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("system time is before the Unix epoch");
+    Timestamp::from_unix(context, now.as_secs(), now.subsec_nanos())
+}
+
+/// Generate a new time-based (v1) UUID, optionally pinning the node id / clock sequence
+#[pyfunction]
+#[pyo3(signature = (node_id=None, clock_seq=None))]
+fn new_v1(node_id: Option<&PyBytes>, clock_seq: Option<u16>) -> PyResult<PyUuid> {
+    let node = resolve_node_id(node_id)?;
+    let ts = now_timestamp(clock_seq);
+    Ok(PyUuid::new(Uuid::new_v1(ts, &node)))
+}
+
+/// Generate a new reordered time-based (v6) UUID, optionally pinning the node id / clock sequence
+#[pyfunction]
+#[pyo3(signature = (node_id=None, clock_seq=None))]
+fn new_v6(node_id: Option<&PyBytes>, clock_seq: Option<u16>) -> PyResult<PyUuid> {
+    let node = resolve_node_id(node_id)?;
+    let ts = now_timestamp(clock_seq);
+    Ok(PyUuid::new(Uuid::new_v6(ts, &node)))
+}
+
+/// Generate a new Unix-timestamp-based (v7) UUID
+#[pyfunction]
+fn new_v7() -> PyResult<PyUuid> {
+    Ok(PyUuid::new(Uuid::now_v7()))
+}
+
+/// Generate a new name-based (v3, MD5) UUID from a namespace and a name
+#[pyfunction]
+fn new_v3(namespace: &PyUuid, name: &str) -> PyResult<PyUuid> {
+    Ok(PyUuid::new(Uuid::new_v3(&namespace.inner, name.as_bytes())))
+}
+
+/// Generate a new name-based (v5, SHA-1) UUID from a namespace and a name
+#[pyfunction]
+fn new_v5(namespace: &PyUuid, name: &str) -> PyResult<PyUuid> {
+    Ok(PyUuid::new(Uuid::new_v5(&namespace.inner, name.as_bytes())))
+}
+
 /// Parse UUID from string
+///
+/// Accepts the simple, hyphenated, braced, and URN forms transparently, with hex digits
+/// in either case; the `urn:uuid:` prefix must be lowercase.
 #[pyfunction]
 fn parse_str(s: &str) -> PyResult<PyUuid> {
-    let uuid = Uuid::from_str(s)
+    let uuid = Uuid::from_str(s.trim())
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
     Ok(PyUuid::new(uuid))
 }
@@ -190,7 +375,10 @@ fn max() -> PyResult<PyUuid> {
 }
 
 /// Check if string is valid UUID
+///
+/// Accepts the simple, hyphenated, braced, and URN forms transparently, with hex digits
+/// in either case; the `urn:uuid:` prefix must be lowercase.
 #[pyfunction]
 fn is_valid(s: &str) -> PyResult<bool> {
-    Ok(Uuid::from_str(s).is_ok())
+    Ok(Uuid::from_str(s.trim()).is_ok())
 }